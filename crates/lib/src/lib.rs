@@ -30,6 +30,10 @@ use std::{collections::HashMap, error::Error};
 ///   lower cased. Values may be lists or singular strings. `true` cfg features are represented as
 ///   empty strings (""). (Cargo does not create `CARGO_CFG_<cfg>` environment variables for boolean
 ///   features whose values are `false`.)
+/// - `target`: Information about the target being built for, useful for ex. sizing regions based
+///   on pointer width or branching on endianness. Contains `target` (the target triple), `arch`,
+///   `os`, `env`, `endian`, `pointer_width` (all from `CARGO_CFG_TARGET_*`), `profile` (`debug` or
+///   `release`) and `opt_level`.
 ///
 /// And custom functions:
 ///
@@ -111,6 +115,7 @@ mod custom_functions {
 #[derive(serde::Serialize)]
 struct LinkerTemplateContext {
     cfg: HashMap<String, TemplateContextCfg>,
+    target: TemplateContextTarget,
 }
 
 impl LinkerTemplateContext {
@@ -122,7 +127,38 @@ impl LinkerTemplateContext {
             })
             .collect();
 
-        Self { cfg }
+        Self { cfg, target: TemplateContextTarget::new() }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct TemplateContextTarget {
+    target: String,
+    arch: String,
+    os: String,
+    env: String,
+    endian: String,
+    pointer_width: String,
+    profile: String,
+    opt_level: String,
+}
+
+impl TemplateContextTarget {
+    fn new() -> Self {
+        fn env_var(name: &str) -> String {
+            std::env::var(name).unwrap_or_else(|err| panic!("{name} environment variable not found: {err}"))
+        }
+
+        Self {
+            target: env_var("TARGET"),
+            arch: env_var("CARGO_CFG_TARGET_ARCH"),
+            os: env_var("CARGO_CFG_TARGET_OS"),
+            env: env_var("CARGO_CFG_TARGET_ENV"),
+            endian: env_var("CARGO_CFG_TARGET_ENDIAN"),
+            pointer_width: env_var("CARGO_CFG_TARGET_POINTER_WIDTH"),
+            profile: env_var("PROFILE"),
+            opt_level: env_var("OPT_LEVEL"),
+        }
     }
 }
 